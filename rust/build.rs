@@ -0,0 +1,22 @@
+use std::env;
+use std::path::PathBuf;
+
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let include_dir = PathBuf::from(&crate_dir).join("include");
+    std::fs::create_dir_all(&include_dir).expect("Unable to create include/ directory");
+    let out_path = include_dir.join("fastembed.h");
+
+    let config = cbindgen::Config::from_file("cbindgen.toml")
+        .expect("Unable to read cbindgen.toml configuration file");
+
+    cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("Unable to generate bindings for the FFI surface in src/lib.rs")
+        .write_to_file(out_path);
+
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+}
@@ -1,14 +1,60 @@
 use fastembed::{
-    EmbeddingModel, ImageEmbedding, ImageEmbeddingModel, ImageInitOptions, InitOptions,
-    RerankInitOptions, RerankerModel, SparseInitOptions, SparseModel, SparseTextEmbedding,
-    TextEmbedding, TextRerank,
+    EmbeddingModel, ImageEmbedding, ImageEmbeddingModel, ImageInitOptions, ImageInitOptionsUserDefined,
+    InitOptions, InitOptionsUserDefined, Pooling, RerankInitOptions, RerankInitOptionsUserDefined,
+    RerankerModel, SparseEmbedding, SparseInitOptions, SparseModel,
+    SparseTextEmbedding, TextEmbedding, TextRerank, TokenizerFiles, UserDefinedEmbeddingModel,
+    UserDefinedImageEmbeddingModel, UserDefinedRerankingModel,
 };
+use ort::execution_providers::{
+    CPUExecutionProvider, CUDAExecutionProvider, CoreMLExecutionProvider, DirectMLExecutionProvider,
+    ExecutionProviderDispatch,
+};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use std::ptr;
 use std::slice;
 use std::time::Instant;
 
+// Execution provider requested for a model constructor, passed across the FFI as an int.
+const EXECUTION_PROVIDER_CPU: i32 = 0;
+const EXECUTION_PROVIDER_CUDA: i32 = 1;
+const EXECUTION_PROVIDER_COREML: i32 = 2;
+const EXECUTION_PROVIDER_DIRECTML: i32 = 3;
+
+fn execution_providers_for(execution_provider: i32) -> Vec<ExecutionProviderDispatch> {
+    match execution_provider {
+        EXECUTION_PROVIDER_CUDA => vec![CUDAExecutionProvider::default().build()],
+        EXECUTION_PROVIDER_COREML => vec![CoreMLExecutionProvider::default().build()],
+        EXECUTION_PROVIDER_DIRECTML => vec![DirectMLExecutionProvider::default().build()],
+        _ => vec![CPUExecutionProvider::default().build()],
+    }
+}
+
+// Loads the four files fastembed's tokenizer expects out of a user-supplied directory.
+fn read_tokenizer_files(tokenizer_dir: &str) -> Result<TokenizerFiles, String> {
+    let dir = std::path::Path::new(tokenizer_dir);
+    let read = |file_name: &str| -> Result<Vec<u8>, String> {
+        std::fs::read(dir.join(file_name)).map_err(|e| format!("Failed to read {}: {}", file_name, e))
+    };
+
+    Ok(TokenizerFiles {
+        tokenizer_file: read("tokenizer.json")?,
+        config_file: read("config.json")?,
+        special_tokens_map_file: read("special_tokens_map.json")?,
+        tokenizer_config_file: read("tokenizer_config.json")?,
+    })
+}
+
+fn pooling_from_int(pooling: i32) -> Option<Pooling> {
+    match pooling {
+        1 => Some(Pooling::Cls),
+        2 => Some(Pooling::Mean),
+        _ => None,
+    }
+}
+
 // Opaque handles for the models
 pub struct TextEmbeddingHandle(Box<TextEmbedding>);
 pub struct SparseTextEmbeddingHandle(Box<SparseTextEmbedding>);
@@ -30,6 +76,7 @@ impl FastEmbedError {
     }
 }
 
+/// Frees a `FastEmbedError` returned through an `error` out-pointer. Safe to call with null.
 #[no_mangle]
 pub extern "C" fn fastembed_error_free(error: *mut FastEmbedError) {
     if !error.is_null() {
@@ -55,9 +102,11 @@ pub struct FloatArrayVec {
     pub len: usize,
 }
 
+// One sparse (SPLADE-style) embedding as parallel `indices`/`values` arrays, since the
+// underlying vector is high-dimensional and mostly zero. Owned by `fastembed_sparse_embedding_vec_free`.
 #[repr(C)]
 pub struct SparseEmbeddingC {
-    pub indices: *mut usize,
+    pub indices: *mut u32,
     pub values: *mut f32,
     pub len: usize,
 }
@@ -82,11 +131,22 @@ pub struct RerankResultVec {
 }
 
 // Text Embedding Functions
+/// On success the handle is always non-null, even if `execution_provider` could not be honored.
+/// Pass a non-null `used_fallback` to learn whether construction silently fell back to CPU; when
+/// it did, `error` (if non-null) is also populated with a non-fatal note describing why.
 #[no_mangle]
 pub extern "C" fn fastembed_text_embedding_new(
     model_name: *const c_char,
+    execution_provider: i32,
+    used_fallback: *mut bool,
     error: *mut *mut FastEmbedError,
 ) -> *mut TextEmbeddingHandle {
+    if !used_fallback.is_null() {
+        unsafe {
+            *used_fallback = false;
+        }
+    }
+
     let model_str = unsafe {
         if model_name.is_null() {
             "BAAI/bge-small-en-v1.5"
@@ -111,25 +171,56 @@ pub extern "C" fn fastembed_text_embedding_new(
         _ => EmbeddingModel::BGESmallENV15, // default
     };
 
-    // NOTE: CoreML execution provider has limited support for transformer models
-    // Most BERT-style operations will fall back to CPU even when CoreML is "available"
-    // See: https://github.com/microsoft/onnxruntime/issues/16934
-    // 
-    // For now, we'll use CPU-only execution which is well-optimized by ONNX Runtime
-    let init_options = InitOptions::new(model);
+    let init_options =
+        InitOptions::new(model.clone()).with_execution_providers(execution_providers_for(execution_provider));
 
     match TextEmbedding::try_new(init_options) {
         Ok(embedding) => {
-            eprintln!("[FASTEMBED-RUST] Text embedding model initialized (CPU-optimized)");
+            eprintln!("[FASTEMBED-RUST] Text embedding model initialized (provider={})", execution_provider);
             Box::into_raw(Box::new(TextEmbeddingHandle(Box::new(embedding))))
         },
         Err(e) => {
-            if !error.is_null() {
-                unsafe {
-                    *error = FastEmbedError::from_string(format!("Failed to create text embedding: {}", e));
+            if execution_provider == EXECUTION_PROVIDER_CPU {
+                if !error.is_null() {
+                    unsafe {
+                        *error = FastEmbedError::from_string(format!("Failed to create text embedding: {}", e));
+                    }
+                }
+                return ptr::null_mut();
+            }
+
+            eprintln!(
+                "[FASTEMBED-RUST] Execution provider {} failed ({}), falling back to CPU",
+                execution_provider, e
+            );
+            let cpu_options =
+                InitOptions::new(model).with_execution_providers(execution_providers_for(EXECUTION_PROVIDER_CPU));
+            match TextEmbedding::try_new(cpu_options) {
+                Ok(embedding) => {
+                    if !used_fallback.is_null() {
+                        unsafe {
+                            *used_fallback = true;
+                        }
+                    }
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!(
+                                "Execution provider {} failed ({}); fell back to CPU",
+                                execution_provider, e
+                            ));
+                        }
+                    }
+                    Box::into_raw(Box::new(TextEmbeddingHandle(Box::new(embedding))))
+                }
+                Err(e2) => {
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!("Failed to create text embedding: {}", e2));
+                        }
+                    }
+                    ptr::null_mut()
                 }
             }
-            ptr::null_mut()
         }
     }
 }
@@ -221,6 +312,152 @@ pub extern "C" fn fastembed_text_embedding_embed(
     }
 }
 
+/// On success the handle is always non-null, even if `execution_provider` could not be honored.
+/// Pass a non-null `used_fallback` to learn whether construction silently fell back to CPU; when
+/// it did, `error` (if non-null) is also populated with a non-fatal note describing why.
+///
+/// `normalize` is accepted but unused: `fastembed`'s user-defined-model path has no
+/// normalization option to set.
+#[no_mangle]
+pub extern "C" fn fastembed_text_embedding_new_from_path(
+    onnx_model_path: *const c_char,
+    tokenizer_dir: *const c_char,
+    pooling: i32,
+    _normalize: bool,
+    max_length: usize,
+    execution_provider: i32,
+    used_fallback: *mut bool,
+    error: *mut *mut FastEmbedError,
+) -> *mut TextEmbeddingHandle {
+    if !used_fallback.is_null() {
+        unsafe {
+            *used_fallback = false;
+        }
+    }
+
+    if onnx_model_path.is_null() || tokenizer_dir.is_null() {
+        if !error.is_null() {
+            unsafe {
+                *error = FastEmbedError::from_string("Null pointer provided".to_string());
+            }
+        }
+        return ptr::null_mut();
+    }
+
+    let onnx_path = unsafe {
+        match CStr::from_ptr(onnx_model_path).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                if !error.is_null() {
+                    *error = FastEmbedError::from_string(format!("Invalid ONNX model path: {}", e));
+                }
+                return ptr::null_mut();
+            }
+        }
+    };
+    let tokenizer_path = unsafe {
+        match CStr::from_ptr(tokenizer_dir).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                if !error.is_null() {
+                    *error = FastEmbedError::from_string(format!("Invalid tokenizer directory: {}", e));
+                }
+                return ptr::null_mut();
+            }
+        }
+    };
+
+    let onnx_file = match std::fs::read(onnx_path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(format!("Failed to read ONNX model: {}", e));
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+
+    let tokenizer_files = match read_tokenizer_files(tokenizer_path) {
+        Ok(files) => files,
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(e);
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+
+    let user_model = UserDefinedEmbeddingModel {
+        onnx_file,
+        tokenizer_files,
+        pooling: pooling_from_int(pooling),
+    };
+
+    let mut init_options = InitOptionsUserDefined::new()
+        .with_execution_providers(execution_providers_for(execution_provider));
+    if max_length > 0 {
+        init_options = init_options.with_max_length(max_length);
+    }
+
+    match TextEmbedding::try_new_from_user_defined(user_model.clone(), init_options) {
+        Ok(embedding) => {
+            eprintln!("[FASTEMBED-RUST] Text embedding model loaded from {}", onnx_path);
+            Box::into_raw(Box::new(TextEmbeddingHandle(Box::new(embedding))))
+        }
+        Err(e) => {
+            if execution_provider == EXECUTION_PROVIDER_CPU {
+                if !error.is_null() {
+                    unsafe {
+                        *error = FastEmbedError::from_string(format!("Failed to load text embedding model: {}", e));
+                    }
+                }
+                return ptr::null_mut();
+            }
+
+            eprintln!(
+                "[FASTEMBED-RUST] Execution provider {} failed ({}), falling back to CPU",
+                execution_provider, e
+            );
+            let mut cpu_options = InitOptionsUserDefined::new()
+                .with_execution_providers(execution_providers_for(EXECUTION_PROVIDER_CPU));
+            if max_length > 0 {
+                cpu_options = cpu_options.with_max_length(max_length);
+            }
+            match TextEmbedding::try_new_from_user_defined(user_model, cpu_options) {
+                Ok(embedding) => {
+                    if !used_fallback.is_null() {
+                        unsafe {
+                            *used_fallback = true;
+                        }
+                    }
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!(
+                                "Execution provider {} failed ({}); fell back to CPU",
+                                execution_provider, e
+                            ));
+                        }
+                    }
+                    Box::into_raw(Box::new(TextEmbeddingHandle(Box::new(embedding))))
+                }
+                Err(e2) => {
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!("Failed to load text embedding model: {}", e2));
+                        }
+                    }
+                    ptr::null_mut()
+                }
+            }
+        }
+    }
+}
+
+/// Frees a handle returned by `fastembed_text_embedding_new` or `fastembed_text_embedding_new_from_path`.
 #[no_mangle]
 pub extern "C" fn fastembed_text_embedding_free(handle: *mut TextEmbeddingHandle) {
     if !handle.is_null() {
@@ -231,11 +468,22 @@ pub extern "C" fn fastembed_text_embedding_free(handle: *mut TextEmbeddingHandle
 }
 
 // Sparse Text Embedding Functions
+/// On success the handle is always non-null, even if `execution_provider` could not be honored.
+/// Pass a non-null `used_fallback` to learn whether construction silently fell back to CPU; when
+/// it did, `error` (if non-null) is also populated with a non-fatal note describing why.
 #[no_mangle]
 pub extern "C" fn fastembed_sparse_text_embedding_new(
     model_name: *const c_char,
+    execution_provider: i32,
+    used_fallback: *mut bool,
     error: *mut *mut FastEmbedError,
 ) -> *mut SparseTextEmbeddingHandle {
+    if !used_fallback.is_null() {
+        unsafe {
+            *used_fallback = false;
+        }
+    }
+
     let model_str = unsafe {
         if model_name.is_null() {
             "Qdrant/Splade_PP_en_v1"
@@ -264,21 +512,59 @@ pub extern "C" fn fastembed_sparse_text_embedding_new(
         }
     };
 
-    // NOTE: CoreML has limited support for transformer models - using CPU-only
-    let init_options = SparseInitOptions::new(model);
+    let init_options = SparseInitOptions::new(model.clone())
+        .with_execution_providers(execution_providers_for(execution_provider));
 
     match SparseTextEmbedding::try_new(init_options) {
         Ok(embedding) => {
-            eprintln!("[FASTEMBED-RUST] Sparse text embedding model initialized (CPU-optimized)");
+            eprintln!("[FASTEMBED-RUST] Sparse text embedding model initialized (provider={})", execution_provider);
             Box::into_raw(Box::new(SparseTextEmbeddingHandle(Box::new(embedding))))
         },
         Err(e) => {
-            if !error.is_null() {
-                unsafe {
-                    *error = FastEmbedError::from_string(format!("Failed to create sparse text embedding: {}", e));
+            if execution_provider == EXECUTION_PROVIDER_CPU {
+                if !error.is_null() {
+                    unsafe {
+                        *error = FastEmbedError::from_string(format!("Failed to create sparse text embedding: {}", e));
+                    }
+                }
+                return ptr::null_mut();
+            }
+
+            eprintln!(
+                "[FASTEMBED-RUST] Execution provider {} failed ({}), falling back to CPU",
+                execution_provider, e
+            );
+            let cpu_options = SparseInitOptions::new(model)
+                .with_execution_providers(execution_providers_for(EXECUTION_PROVIDER_CPU));
+            match SparseTextEmbedding::try_new(cpu_options) {
+                Ok(embedding) => {
+                    if !used_fallback.is_null() {
+                        unsafe {
+                            *used_fallback = true;
+                        }
+                    }
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!(
+                                "Execution provider {} failed ({}); fell back to CPU",
+                                execution_provider, e
+                            ));
+                        }
+                    }
+                    Box::into_raw(Box::new(SparseTextEmbeddingHandle(Box::new(embedding))))
+                }
+                Err(e2) => {
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!(
+                                "Failed to create sparse text embedding: {}",
+                                e2
+                            ));
+                        }
+                    }
+                    ptr::null_mut()
                 }
             }
-            ptr::null_mut()
         }
     }
 }
@@ -342,7 +628,7 @@ pub extern "C" fn fastembed_sparse_text_embedding_embed(
             let mut sparse_embs: Vec<SparseEmbeddingC> = embeddings
                 .into_iter()
                 .map(|emb| {
-                    let mut indices_vec = emb.indices;
+                    let mut indices_vec: Vec<u32> = emb.indices.into_iter().map(|i| i as u32).collect();
                     let mut values_vec = emb.values;
                     let len = indices_vec.len();
                     let indices_ptr = indices_vec.as_mut_ptr();
@@ -377,6 +663,41 @@ pub extern "C" fn fastembed_sparse_text_embedding_embed(
     }
 }
 
+/// Always fails: `fastembed` 4.1.0's `SparseTextEmbedding` has no user-defined-model
+/// constructor (unlike `TextEmbedding`/`ImageEmbedding`/`TextRerank`, which all expose
+/// `try_new_from_user_defined`), so loading a sparse model from a local ONNX file isn't
+/// implemented here. Kept as a stub, rather than
+/// removed, so the Go bindings' function table stays uniform across the four families;
+/// revisit once upstream fastembed adds the equivalent entry point for sparse models.
+#[no_mangle]
+pub extern "C" fn fastembed_sparse_text_embedding_new_from_path(
+    _onnx_model_path: *const c_char,
+    _tokenizer_dir: *const c_char,
+    _pooling: i32,
+    _max_length: usize,
+    _execution_provider: i32,
+    used_fallback: *mut bool,
+    error: *mut *mut FastEmbedError,
+) -> *mut SparseTextEmbeddingHandle {
+    if !used_fallback.is_null() {
+        unsafe {
+            *used_fallback = false;
+        }
+    }
+
+    if !error.is_null() {
+        unsafe {
+            *error = FastEmbedError::from_string(
+                "Loading a sparse text embedding model from a local path is not supported: \
+                 fastembed's SparseTextEmbedding has no user-defined-model constructor"
+                    .to_string(),
+            );
+        }
+    }
+    ptr::null_mut()
+}
+
+/// Frees a handle returned by `fastembed_sparse_text_embedding_new` or `fastembed_sparse_text_embedding_new_from_path`.
 #[no_mangle]
 pub extern "C" fn fastembed_sparse_text_embedding_free(handle: *mut SparseTextEmbeddingHandle) {
     if !handle.is_null() {
@@ -387,11 +708,22 @@ pub extern "C" fn fastembed_sparse_text_embedding_free(handle: *mut SparseTextEm
 }
 
 // Image Embedding Functions
+/// On success the handle is always non-null, even if `execution_provider` could not be honored.
+/// Pass a non-null `used_fallback` to learn whether construction silently fell back to CPU; when
+/// it did, `error` (if non-null) is also populated with a non-fatal note describing why.
 #[no_mangle]
 pub extern "C" fn fastembed_image_embedding_new(
     model_name: *const c_char,
+    execution_provider: i32,
+    used_fallback: *mut bool,
     error: *mut *mut FastEmbedError,
 ) -> *mut ImageEmbeddingHandle {
+    if !used_fallback.is_null() {
+        unsafe {
+            *used_fallback = false;
+        }
+    }
+
     let model_str = unsafe {
         if model_name.is_null() {
             "Qdrant/clip-ViT-B-32-vision"
@@ -420,15 +752,53 @@ pub extern "C" fn fastembed_image_embedding_new(
         }
     };
 
-    match ImageEmbedding::try_new(ImageInitOptions::new(model)) {
+    let init_options = ImageInitOptions::new(model.clone())
+        .with_execution_providers(execution_providers_for(execution_provider));
+
+    match ImageEmbedding::try_new(init_options) {
         Ok(embedding) => Box::into_raw(Box::new(ImageEmbeddingHandle(Box::new(embedding)))),
         Err(e) => {
-            if !error.is_null() {
-                unsafe {
-                    *error = FastEmbedError::from_string(format!("Failed to create image embedding: {}", e));
+            if execution_provider == EXECUTION_PROVIDER_CPU {
+                if !error.is_null() {
+                    unsafe {
+                        *error = FastEmbedError::from_string(format!("Failed to create image embedding: {}", e));
+                    }
+                }
+                return ptr::null_mut();
+            }
+
+            eprintln!(
+                "[FASTEMBED-RUST] Execution provider {} failed ({}), falling back to CPU",
+                execution_provider, e
+            );
+            let cpu_options = ImageInitOptions::new(model)
+                .with_execution_providers(execution_providers_for(EXECUTION_PROVIDER_CPU));
+            match ImageEmbedding::try_new(cpu_options) {
+                Ok(embedding) => {
+                    if !used_fallback.is_null() {
+                        unsafe {
+                            *used_fallback = true;
+                        }
+                    }
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!(
+                                "Execution provider {} failed ({}); fell back to CPU",
+                                execution_provider, e
+                            ));
+                        }
+                    }
+                    Box::into_raw(Box::new(ImageEmbeddingHandle(Box::new(embedding))))
+                }
+                Err(e2) => {
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!("Failed to create image embedding: {}", e2));
+                        }
+                    }
+                    ptr::null_mut()
                 }
             }
-            ptr::null_mut()
         }
     }
 }
@@ -512,6 +882,141 @@ pub extern "C" fn fastembed_image_embedding_embed(
     }
 }
 
+/// On success the handle is always non-null, even if `execution_provider` could not be honored.
+/// Pass a non-null `used_fallback` to learn whether construction silently fell back to CPU; when
+/// it did, `error` (if non-null) is also populated with a non-fatal note describing why.
+#[no_mangle]
+pub extern "C" fn fastembed_image_embedding_new_from_path(
+    onnx_model_path: *const c_char,
+    preprocessor_dir: *const c_char,
+    execution_provider: i32,
+    used_fallback: *mut bool,
+    error: *mut *mut FastEmbedError,
+) -> *mut ImageEmbeddingHandle {
+    if !used_fallback.is_null() {
+        unsafe {
+            *used_fallback = false;
+        }
+    }
+
+    if onnx_model_path.is_null() || preprocessor_dir.is_null() {
+        if !error.is_null() {
+            unsafe {
+                *error = FastEmbedError::from_string("Null pointer provided".to_string());
+            }
+        }
+        return ptr::null_mut();
+    }
+
+    let onnx_path = unsafe {
+        match CStr::from_ptr(onnx_model_path).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                if !error.is_null() {
+                    *error = FastEmbedError::from_string(format!("Invalid ONNX model path: {}", e));
+                }
+                return ptr::null_mut();
+            }
+        }
+    };
+    let preprocessor_path = unsafe {
+        match CStr::from_ptr(preprocessor_dir).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                if !error.is_null() {
+                    *error = FastEmbedError::from_string(format!("Invalid preprocessor directory: {}", e));
+                }
+                return ptr::null_mut();
+            }
+        }
+    };
+
+    let onnx_file = match std::fs::read(onnx_path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(format!("Failed to read ONNX model: {}", e));
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+
+    let preprocessor_file = match std::fs::read(
+        std::path::Path::new(preprocessor_path).join("preprocessor_config.json"),
+    ) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(format!("Failed to read preprocessor config: {}", e));
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+
+    let user_model = UserDefinedImageEmbeddingModel {
+        onnx_file,
+        preprocessor_file,
+    };
+
+    let init_options = ImageInitOptionsUserDefined::new()
+        .with_execution_providers(execution_providers_for(execution_provider));
+
+    match ImageEmbedding::try_new_from_user_defined(user_model.clone(), init_options) {
+        Ok(embedding) => {
+            eprintln!("[FASTEMBED-RUST] Image embedding model loaded from {}", onnx_path);
+            Box::into_raw(Box::new(ImageEmbeddingHandle(Box::new(embedding))))
+        }
+        Err(e) => {
+            if execution_provider == EXECUTION_PROVIDER_CPU {
+                if !error.is_null() {
+                    unsafe {
+                        *error = FastEmbedError::from_string(format!("Failed to load image embedding model: {}", e));
+                    }
+                }
+                return ptr::null_mut();
+            }
+
+            eprintln!(
+                "[FASTEMBED-RUST] Execution provider {} failed ({}), falling back to CPU",
+                execution_provider, e
+            );
+            let cpu_options = ImageInitOptionsUserDefined::new()
+                .with_execution_providers(execution_providers_for(EXECUTION_PROVIDER_CPU));
+            match ImageEmbedding::try_new_from_user_defined(user_model, cpu_options) {
+                Ok(embedding) => {
+                    if !used_fallback.is_null() {
+                        unsafe {
+                            *used_fallback = true;
+                        }
+                    }
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!(
+                                "Execution provider {} failed ({}); fell back to CPU",
+                                execution_provider, e
+                            ));
+                        }
+                    }
+                    Box::into_raw(Box::new(ImageEmbeddingHandle(Box::new(embedding))))
+                }
+                Err(e2) => {
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!("Failed to load image embedding model: {}", e2));
+                        }
+                    }
+                    ptr::null_mut()
+                }
+            }
+        }
+    }
+}
+
+/// Frees a handle returned by `fastembed_image_embedding_new` or `fastembed_image_embedding_new_from_path`.
 #[no_mangle]
 pub extern "C" fn fastembed_image_embedding_free(handle: *mut ImageEmbeddingHandle) {
     if !handle.is_null() {
@@ -522,11 +1027,22 @@ pub extern "C" fn fastembed_image_embedding_free(handle: *mut ImageEmbeddingHand
 }
 
 // Text Rerank Functions
+/// On success the handle is always non-null, even if `execution_provider` could not be honored.
+/// Pass a non-null `used_fallback` to learn whether construction silently fell back to CPU; when
+/// it did, `error` (if non-null) is also populated with a non-fatal note describing why.
 #[no_mangle]
 pub extern "C" fn fastembed_text_rerank_new(
     model_name: *const c_char,
+    execution_provider: i32,
+    used_fallback: *mut bool,
     error: *mut *mut FastEmbedError,
 ) -> *mut TextRerankHandle {
+    if !used_fallback.is_null() {
+        unsafe {
+            *used_fallback = false;
+        }
+    }
+
     let model_str = unsafe {
         if model_name.is_null() {
             "BAAI/bge-reranker-base"
@@ -555,28 +1071,63 @@ pub extern "C" fn fastembed_text_rerank_new(
         }
     };
 
-    // NOTE: CoreML has limited support for transformer models - using CPU-only
-    let init_options = RerankInitOptions::new(model);
+    let init_options = RerankInitOptions::new(model.clone())
+        .with_execution_providers(execution_providers_for(execution_provider));
 
     match TextRerank::try_new(init_options) {
         Ok(reranker) => {
-            eprintln!("[FASTEMBED-RUST] Text reranker model initialized (CPU-optimized)");
+            eprintln!("[FASTEMBED-RUST] Text reranker model initialized (provider={})", execution_provider);
             Box::into_raw(Box::new(TextRerankHandle(Box::new(reranker))))
         },
         Err(e) => {
-            if !error.is_null() {
-                unsafe {
-                    *error = FastEmbedError::from_string(format!("Failed to create text reranker: {}", e));
+            if execution_provider == EXECUTION_PROVIDER_CPU {
+                if !error.is_null() {
+                    unsafe {
+                        *error = FastEmbedError::from_string(format!("Failed to create text reranker: {}", e));
+                    }
                 }
+                return ptr::null_mut();
             }
-            ptr::null_mut()
-        }
-    }
-}
 
-#[no_mangle]
-pub extern "C" fn fastembed_text_rerank_rerank(
-    handle: *mut TextRerankHandle,
+            eprintln!(
+                "[FASTEMBED-RUST] Execution provider {} failed ({}), falling back to CPU",
+                execution_provider, e
+            );
+            let cpu_options = RerankInitOptions::new(model)
+                .with_execution_providers(execution_providers_for(EXECUTION_PROVIDER_CPU));
+            match TextRerank::try_new(cpu_options) {
+                Ok(reranker) => {
+                    if !used_fallback.is_null() {
+                        unsafe {
+                            *used_fallback = true;
+                        }
+                    }
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!(
+                                "Execution provider {} failed ({}); fell back to CPU",
+                                execution_provider, e
+                            ));
+                        }
+                    }
+                    Box::into_raw(Box::new(TextRerankHandle(Box::new(reranker))))
+                }
+                Err(e2) => {
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!("Failed to create text reranker: {}", e2));
+                        }
+                    }
+                    ptr::null_mut()
+                }
+            }
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn fastembed_text_rerank_rerank(
+    handle: *mut TextRerankHandle,
     query: *const c_char,
     documents: *const *const c_char,
     num_documents: usize,
@@ -677,6 +1228,150 @@ pub extern "C" fn fastembed_text_rerank_rerank(
     }
 }
 
+/// On success the handle is always non-null, even if `execution_provider` could not be honored.
+/// Pass a non-null `used_fallback` to learn whether construction silently fell back to CPU; when
+/// it did, `error` (if non-null) is also populated with a non-fatal note describing why.
+#[no_mangle]
+pub extern "C" fn fastembed_text_rerank_new_from_path(
+    onnx_model_path: *const c_char,
+    tokenizer_dir: *const c_char,
+    max_length: usize,
+    execution_provider: i32,
+    used_fallback: *mut bool,
+    error: *mut *mut FastEmbedError,
+) -> *mut TextRerankHandle {
+    if !used_fallback.is_null() {
+        unsafe {
+            *used_fallback = false;
+        }
+    }
+
+    if onnx_model_path.is_null() || tokenizer_dir.is_null() {
+        if !error.is_null() {
+            unsafe {
+                *error = FastEmbedError::from_string("Null pointer provided".to_string());
+            }
+        }
+        return ptr::null_mut();
+    }
+
+    let onnx_path = unsafe {
+        match CStr::from_ptr(onnx_model_path).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                if !error.is_null() {
+                    *error = FastEmbedError::from_string(format!("Invalid ONNX model path: {}", e));
+                }
+                return ptr::null_mut();
+            }
+        }
+    };
+    let tokenizer_path = unsafe {
+        match CStr::from_ptr(tokenizer_dir).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                if !error.is_null() {
+                    *error = FastEmbedError::from_string(format!("Invalid tokenizer directory: {}", e));
+                }
+                return ptr::null_mut();
+            }
+        }
+    };
+
+    let onnx_file = match std::fs::read(onnx_path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(format!("Failed to read ONNX model: {}", e));
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+
+    let tokenizer_files = match read_tokenizer_files(tokenizer_path) {
+        Ok(files) => files,
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(e);
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+
+    let user_model = UserDefinedRerankingModel {
+        onnx_file,
+        tokenizer_files,
+    };
+
+    let mut init_options = RerankInitOptionsUserDefined {
+        execution_providers: execution_providers_for(execution_provider),
+        ..Default::default()
+    };
+    if max_length > 0 {
+        init_options.max_length = max_length;
+    }
+
+    match TextRerank::try_new_from_user_defined(user_model.clone(), init_options) {
+        Ok(reranker) => {
+            eprintln!("[FASTEMBED-RUST] Text reranker model loaded from {}", onnx_path);
+            Box::into_raw(Box::new(TextRerankHandle(Box::new(reranker))))
+        }
+        Err(e) => {
+            if execution_provider == EXECUTION_PROVIDER_CPU {
+                if !error.is_null() {
+                    unsafe {
+                        *error = FastEmbedError::from_string(format!("Failed to load text reranker model: {}", e));
+                    }
+                }
+                return ptr::null_mut();
+            }
+
+            eprintln!(
+                "[FASTEMBED-RUST] Execution provider {} failed ({}), falling back to CPU",
+                execution_provider, e
+            );
+            let mut cpu_options = RerankInitOptionsUserDefined {
+                execution_providers: execution_providers_for(EXECUTION_PROVIDER_CPU),
+                ..Default::default()
+            };
+            if max_length > 0 {
+                cpu_options.max_length = max_length;
+            }
+            match TextRerank::try_new_from_user_defined(user_model, cpu_options) {
+                Ok(reranker) => {
+                    if !used_fallback.is_null() {
+                        unsafe {
+                            *used_fallback = true;
+                        }
+                    }
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!(
+                                "Execution provider {} failed ({}); fell back to CPU",
+                                execution_provider, e
+                            ));
+                        }
+                    }
+                    Box::into_raw(Box::new(TextRerankHandle(Box::new(reranker))))
+                }
+                Err(e2) => {
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!("Failed to load text reranker model: {}", e2));
+                        }
+                    }
+                    ptr::null_mut()
+                }
+            }
+        }
+    }
+}
+
+/// Frees a handle returned by `fastembed_text_rerank_new` or `fastembed_text_rerank_new_from_path`.
 #[no_mangle]
 pub extern "C" fn fastembed_text_rerank_free(handle: *mut TextRerankHandle) {
     if !handle.is_null() {
@@ -686,7 +1381,855 @@ pub extern "C" fn fastembed_text_rerank_free(handle: *mut TextRerankHandle) {
     }
 }
 
+// Hybrid Search Functions
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+fn sparse_dot(query: &SparseEmbedding, doc: &SparseEmbedding) -> f32 {
+    // Dedup indices (a sparse vector should not repeat an index, but guard anyway)
+    // so two entries for the same dimension don't double count.
+    let mut doc_map: HashMap<usize, f32> = HashMap::with_capacity(doc.indices.len());
+    for (idx, val) in doc.indices.iter().zip(doc.values.iter()) {
+        doc_map.insert(*idx, *val);
+    }
+
+    let mut seen: HashMap<usize, ()> = HashMap::with_capacity(query.indices.len());
+    query
+        .indices
+        .iter()
+        .zip(query.values.iter())
+        .filter(|(idx, _)| seen.insert(**idx, ()).is_none())
+        .filter_map(|(idx, val)| doc_map.get(idx).map(|other| val * other))
+        .sum()
+}
+
+// Returns the 1-based rank of each document within `scores`, in descending order.
+fn ranks_from_scores(scores: &[f32]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    order.sort_by(|&a, &b| scores[b].partial_cmp(&scores[a]).unwrap_or(Ordering::Equal));
+
+    let mut ranks = vec![0usize; scores.len()];
+    for (rank, doc_idx) in order.into_iter().enumerate() {
+        ranks[doc_idx] = rank + 1;
+    }
+    ranks
+}
+
+#[no_mangle]
+pub extern "C" fn fastembed_hybrid_search(
+    dense_handle: *mut TextEmbeddingHandle,
+    sparse_handle: *mut SparseTextEmbeddingHandle,
+    query: *const c_char,
+    documents: *const *const c_char,
+    num_documents: usize,
+    top_k: usize,
+    rrf_k: f32,
+    error: *mut *mut FastEmbedError,
+) -> *mut RerankResultVec {
+    if dense_handle.is_null() || sparse_handle.is_null() || query.is_null() || documents.is_null() {
+        if !error.is_null() {
+            unsafe {
+                *error = FastEmbedError::from_string("Null pointer provided".to_string());
+            }
+        }
+        return ptr::null_mut();
+    }
+
+    let dense_handle = unsafe { &mut *dense_handle };
+    let sparse_handle = unsafe { &mut *sparse_handle };
+
+    let query_str = unsafe {
+        match CStr::from_ptr(query).to_str() {
+            Ok(s) => s.to_string(),
+            Err(e) => {
+                if !error.is_null() {
+                    *error = FastEmbedError::from_string(format!("Invalid UTF-8 in query: {}", e));
+                }
+                return ptr::null_mut();
+            }
+        }
+    };
+
+    let doc_slice = unsafe { slice::from_raw_parts(documents, num_documents) };
+    let mut doc_strings = Vec::new();
+    for &doc_ptr in doc_slice {
+        if doc_ptr.is_null() {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string("Null document pointer in array".to_string());
+                }
+            }
+            return ptr::null_mut();
+        }
+        let doc = unsafe { CStr::from_ptr(doc_ptr).to_str() };
+        match doc {
+            Ok(s) => doc_strings.push(s.to_string()),
+            Err(e) => {
+                if !error.is_null() {
+                    unsafe {
+                        *error = FastEmbedError::from_string(format!("Invalid UTF-8 in document: {}", e));
+                    }
+                }
+                return ptr::null_mut();
+            }
+        }
+    }
+
+    if doc_strings.is_empty() {
+        return Box::into_raw(Box::new(RerankResultVec {
+            results: ptr::null_mut(),
+            len: 0,
+        }));
+    }
+
+    let rrf_k = if rrf_k > 0.0 { rrf_k } else { 60.0 };
+
+    // Dense ranking: embed the query alongside every document, then rank documents
+    // by cosine similarity of their dense vector to the query's dense vector.
+    let mut dense_inputs = Vec::with_capacity(doc_strings.len() + 1);
+    dense_inputs.push(query_str.clone());
+    dense_inputs.extend(doc_strings.iter().cloned());
+
+    let dense_embeddings = match dense_handle.0.embed(dense_inputs, None) {
+        Ok(embeddings) => embeddings,
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(format!("Dense embedding failed: {}", e));
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+    let dense_scores: Vec<f32> = dense_embeddings[1..]
+        .iter()
+        .map(|doc_emb| cosine_similarity(&dense_embeddings[0], doc_emb))
+        .collect();
+    let dense_ranks = ranks_from_scores(&dense_scores);
+
+    // Sparse ranking: same idea, but similarity is the dot product over the
+    // overlapping indices of the SPLADE-style sparse vectors.
+    let mut sparse_inputs = Vec::with_capacity(doc_strings.len() + 1);
+    sparse_inputs.push(query_str);
+    sparse_inputs.extend(doc_strings.iter().cloned());
+
+    let sparse_embeddings = match sparse_handle.0.embed(sparse_inputs, None) {
+        Ok(embeddings) => embeddings,
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(format!("Sparse embedding failed: {}", e));
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+    let sparse_scores: Vec<f32> = sparse_embeddings[1..]
+        .iter()
+        .map(|doc_emb| sparse_dot(&sparse_embeddings[0], doc_emb))
+        .collect();
+    let sparse_ranks = ranks_from_scores(&sparse_scores);
+
+    // `dense_embeddings` and `sparse_embeddings` are plain Vecs owned by this
+    // function and drop at the end of scope, so no manual cleanup is needed
+    // on either the success or error path above.
+
+    // Reciprocal Rank Fusion: score(d) = sum over each list of 1 / (rrf_k + rank_d).
+    let fused_scores: Vec<f32> = (0..doc_strings.len())
+        .map(|i| 1.0 / (rrf_k + dense_ranks[i] as f32) + 1.0 / (rrf_k + sparse_ranks[i] as f32))
+        .collect();
+
+    let mut order: Vec<usize> = (0..doc_strings.len()).collect();
+    order.sort_by(|&a, &b| {
+        fused_scores[b]
+            .partial_cmp(&fused_scores[a])
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let k = top_k.min(order.len());
+    let mut c_results: Vec<RerankResultC> = order[..k]
+        .iter()
+        .map(|&idx| RerankResultC {
+            index: idx,
+            score: fused_scores[idx],
+            document: ptr::null_mut(),
+        })
+        .collect();
+
+    let len = c_results.len();
+    let results_ptr = c_results.as_mut_ptr();
+    std::mem::forget(c_results);
+
+    Box::into_raw(Box::new(RerankResultVec {
+        results: results_ptr,
+        len,
+    }))
+}
+
+// Min-max normalizes `scores` to [0, 1]; a degenerate (all-equal) range maps to 0.5
+// for every entry instead of dividing by zero.
+fn min_max_normalize(scores: &[f32]) -> Vec<f32> {
+    let min = scores.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = scores.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    if max <= min {
+        return vec![0.5; scores.len()];
+    }
+    scores.iter().map(|&s| (s - min) / (max - min)).collect()
+}
+
+#[repr(C)]
+pub struct HybridScoreC {
+    pub index: usize,
+    pub score: f32,
+}
+
+#[repr(C)]
+pub struct HybridScoreVec {
+    pub scores: *mut HybridScoreC,
+    pub len: usize,
+}
+
+// Convex-combination alternative to `fastembed_hybrid_search`'s rank fusion: blends
+// normalized dense and sparse similarity scores directly, giving callers continuous
+// control over the semantic/lexical balance via `semantic_ratio` instead of a rank-only mix.
+#[no_mangle]
+pub extern "C" fn fastembed_hybrid_search_weighted(
+    dense_handle: *mut TextEmbeddingHandle,
+    sparse_handle: *mut SparseTextEmbeddingHandle,
+    query: *const c_char,
+    documents: *const *const c_char,
+    num_documents: usize,
+    semantic_ratio: f32,
+    error: *mut *mut FastEmbedError,
+) -> *mut HybridScoreVec {
+    if dense_handle.is_null() || sparse_handle.is_null() || query.is_null() || documents.is_null() {
+        if !error.is_null() {
+            unsafe {
+                *error = FastEmbedError::from_string("Null pointer provided".to_string());
+            }
+        }
+        return ptr::null_mut();
+    }
+
+    let dense_handle = unsafe { &mut *dense_handle };
+    let sparse_handle = unsafe { &mut *sparse_handle };
+
+    let query_str = unsafe {
+        match CStr::from_ptr(query).to_str() {
+            Ok(s) => s.to_string(),
+            Err(e) => {
+                if !error.is_null() {
+                    *error = FastEmbedError::from_string(format!("Invalid UTF-8 in query: {}", e));
+                }
+                return ptr::null_mut();
+            }
+        }
+    };
+
+    let doc_slice = unsafe { slice::from_raw_parts(documents, num_documents) };
+    let mut doc_strings = Vec::new();
+    for &doc_ptr in doc_slice {
+        if doc_ptr.is_null() {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string("Null document pointer in array".to_string());
+                }
+            }
+            return ptr::null_mut();
+        }
+        let doc = unsafe { CStr::from_ptr(doc_ptr).to_str() };
+        match doc {
+            Ok(s) => doc_strings.push(s.to_string()),
+            Err(e) => {
+                if !error.is_null() {
+                    unsafe {
+                        *error = FastEmbedError::from_string(format!("Invalid UTF-8 in document: {}", e));
+                    }
+                }
+                return ptr::null_mut();
+            }
+        }
+    }
+
+    if doc_strings.is_empty() {
+        return Box::into_raw(Box::new(HybridScoreVec {
+            scores: ptr::null_mut(),
+            len: 0,
+        }));
+    }
+
+    let ratio = semantic_ratio.clamp(0.0, 1.0);
+
+    let mut dense_inputs = Vec::with_capacity(doc_strings.len() + 1);
+    dense_inputs.push(query_str.clone());
+    dense_inputs.extend(doc_strings.iter().cloned());
+    let dense_embeddings = match dense_handle.0.embed(dense_inputs, None) {
+        Ok(embeddings) => embeddings,
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(format!("Dense embedding failed: {}", e));
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+    let dense_scores: Vec<f32> = dense_embeddings[1..]
+        .iter()
+        .map(|doc_emb| cosine_similarity(&dense_embeddings[0], doc_emb))
+        .collect();
+
+    let mut sparse_inputs = Vec::with_capacity(doc_strings.len() + 1);
+    sparse_inputs.push(query_str);
+    sparse_inputs.extend(doc_strings.iter().cloned());
+    let sparse_embeddings = match sparse_handle.0.embed(sparse_inputs, None) {
+        Ok(embeddings) => embeddings,
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(format!("Sparse embedding failed: {}", e));
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+    let sparse_scores: Vec<f32> = sparse_embeddings[1..]
+        .iter()
+        .map(|doc_emb| sparse_dot(&sparse_embeddings[0], doc_emb))
+        .collect();
+
+    let dense_norm = min_max_normalize(&dense_scores);
+    let sparse_norm = min_max_normalize(&sparse_scores);
+
+    let fused_scores: Vec<f32> = (0..doc_strings.len())
+        .map(|i| ratio * dense_norm[i] + (1.0 - ratio) * sparse_norm[i])
+        .collect();
+
+    let mut order: Vec<usize> = (0..doc_strings.len()).collect();
+    order.sort_by(|&a, &b| {
+        fused_scores[b]
+            .partial_cmp(&fused_scores[a])
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut c_scores: Vec<HybridScoreC> = order
+        .iter()
+        .map(|&idx| HybridScoreC {
+            index: idx,
+            score: fused_scores[idx],
+        })
+        .collect();
+
+    let len = c_scores.len();
+    let scores_ptr = c_scores.as_mut_ptr();
+    std::mem::forget(c_scores);
+
+    Box::into_raw(Box::new(HybridScoreVec {
+        scores: scores_ptr,
+        len,
+    }))
+}
+
+/// Frees a `HybridScoreVec` returned by `fastembed_hybrid_search_weighted`.
+#[no_mangle]
+pub extern "C" fn fastembed_hybrid_score_vec_free(vec: *mut HybridScoreVec) {
+    if !vec.is_null() {
+        unsafe {
+            let vec = Box::from_raw(vec);
+            let _ = Vec::from_raw_parts(vec.scores, vec.len, vec.len);
+        }
+    }
+}
+
+#[repr(C)]
+pub struct RankFusionResultVec {
+    pub indices: *mut usize,
+    pub len: usize,
+}
+
+// Accumulates RRF scores across `lists` (each already ranked best-first) and returns
+// document indices sorted best-first, ties broken by ascending doc id for determinism.
+fn rank_fusion_scores(lists: &[&[usize]], k: f32) -> Vec<usize> {
+    let mut scores: HashMap<usize, f32> = HashMap::new();
+    for entries in lists {
+        for (rank, &doc_id) in entries.iter().enumerate() {
+            *scores.entry(doc_id).or_insert(0.0) += 1.0 / (k + rank as f32);
+        }
+    }
+
+    let mut order: Vec<usize> = scores.keys().cloned().collect();
+    order.sort_by(|&a, &b| {
+        scores[&b]
+            .partial_cmp(&scores[&a])
+            .unwrap_or(Ordering::Equal)
+            .then(a.cmp(&b))
+    });
+    order
+}
+
+// Rank-only Reciprocal Rank Fusion for merging already-ranked document-index lists
+// (e.g. one from a dense embedder, one from a sparse embedder, one from the reranker)
+// without needing comparable raw scores across the families this crate exposes.
+#[no_mangle]
+pub extern "C" fn fastembed_rank_fusion(
+    lists: *const *const usize,
+    num_lists: usize,
+    list_len: *const usize,
+    k: f32,
+    error: *mut *mut FastEmbedError,
+) -> *mut RankFusionResultVec {
+    if lists.is_null() || list_len.is_null() {
+        if !error.is_null() {
+            unsafe {
+                *error = FastEmbedError::from_string("Null pointer provided".to_string());
+            }
+        }
+        return ptr::null_mut();
+    }
+
+    let k = if k > 0.0 { k } else { 60.0 };
+
+    let list_ptrs = unsafe { slice::from_raw_parts(lists, num_lists) };
+    let list_lens = unsafe { slice::from_raw_parts(list_len, num_lists) };
+
+    let lists: Vec<&[usize]> = list_ptrs
+        .iter()
+        .zip(list_lens.iter())
+        .filter(|(&list_ptr, _)| !list_ptr.is_null())
+        .map(|(&list_ptr, &len)| unsafe { slice::from_raw_parts(list_ptr, len) })
+        .collect();
+
+    let mut indices = rank_fusion_scores(&lists, k);
+    let len = indices.len();
+    let indices_ptr = indices.as_mut_ptr();
+    std::mem::forget(indices);
+
+    Box::into_raw(Box::new(RankFusionResultVec {
+        indices: indices_ptr,
+        len,
+    }))
+}
+
+/// Frees a `RankFusionResultVec` returned by `fastembed_rank_fusion`.
+#[no_mangle]
+pub extern "C" fn fastembed_rank_fusion_result_vec_free(vec: *mut RankFusionResultVec) {
+    if !vec.is_null() {
+        unsafe {
+            let vec = Box::from_raw(vec);
+            let _ = Vec::from_raw_parts(vec.indices, vec.len, vec.len);
+        }
+    }
+}
+
+// Auto Embedder Functions
+const AUTO_EMBEDDER_KIND_DENSE: i32 = 0;
+const AUTO_EMBEDDER_KIND_SPARSE: i32 = 1;
+const AUTO_EMBEDDER_KIND_IMAGE: i32 = 2;
+
+enum AutoEmbedderModel {
+    Dense(Box<TextEmbedding>),
+    Sparse(Box<SparseTextEmbedding>),
+    Image(Box<ImageEmbedding>),
+}
+
+pub struct AutoEmbedderHandle {
+    kind: i32,
+    model: AutoEmbedderModel,
+}
+
+// Scans the listing already surfaced by `fastembed_*_list_supported_models` to
+// determine which model family a code belongs to, so callers don't have to
+// maintain their own model-code-to-family table.
+fn detect_auto_embedder_kind(model_code: &str) -> Option<i32> {
+    if TextEmbedding::list_supported_models()
+        .iter()
+        .any(|m| m.model_code == model_code)
+    {
+        Some(AUTO_EMBEDDER_KIND_DENSE)
+    } else if SparseTextEmbedding::list_supported_models()
+        .iter()
+        .any(|m| m.model_code == model_code)
+    {
+        Some(AUTO_EMBEDDER_KIND_SPARSE)
+    } else if ImageEmbedding::list_supported_models()
+        .iter()
+        .any(|m| m.model_code == model_code)
+    {
+        Some(AUTO_EMBEDDER_KIND_IMAGE)
+    } else {
+        None
+    }
+}
+
+/// Honors `execution_provider` the same way the per-family constructors do (see
+/// `fastembed_text_embedding_new`): on success the handle is always non-null, and a non-null
+/// `used_fallback` reports whether construction silently fell back to CPU.
+#[no_mangle]
+pub extern "C" fn fastembed_embed_auto_new(
+    model_code: *const c_char,
+    execution_provider: i32,
+    used_fallback: *mut bool,
+    error: *mut *mut FastEmbedError,
+) -> *mut AutoEmbedderHandle {
+    if !used_fallback.is_null() {
+        unsafe {
+            *used_fallback = false;
+        }
+    }
+
+    if model_code.is_null() {
+        if !error.is_null() {
+            unsafe {
+                *error = FastEmbedError::from_string("Null pointer provided".to_string());
+            }
+        }
+        return ptr::null_mut();
+    }
+
+    let code = unsafe {
+        match CStr::from_ptr(model_code).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                if !error.is_null() {
+                    *error = FastEmbedError::from_string(format!("Invalid model code: {}", e));
+                }
+                return ptr::null_mut();
+            }
+        }
+    };
+
+    match detect_auto_embedder_kind(code) {
+        Some(AUTO_EMBEDDER_KIND_DENSE) => {
+            let model = match code.parse::<EmbeddingModel>() {
+                Ok(m) => m,
+                Err(e) => {
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!("Invalid dense model: {}", e));
+                        }
+                    }
+                    return ptr::null_mut();
+                }
+            };
+            let init_options = InitOptions::new(model.clone())
+                .with_execution_providers(execution_providers_for(execution_provider));
+            match TextEmbedding::try_new(init_options) {
+                Ok(embedding) => Box::into_raw(Box::new(AutoEmbedderHandle {
+                    kind: AUTO_EMBEDDER_KIND_DENSE,
+                    model: AutoEmbedderModel::Dense(Box::new(embedding)),
+                })),
+                Err(e) => {
+                    if execution_provider == EXECUTION_PROVIDER_CPU {
+                        if !error.is_null() {
+                            unsafe {
+                                *error =
+                                    FastEmbedError::from_string(format!("Failed to create text embedding: {}", e));
+                            }
+                        }
+                        return ptr::null_mut();
+                    }
+                    let cpu_options = InitOptions::new(model)
+                        .with_execution_providers(execution_providers_for(EXECUTION_PROVIDER_CPU));
+                    match TextEmbedding::try_new(cpu_options) {
+                        Ok(embedding) => {
+                            if !used_fallback.is_null() {
+                                unsafe {
+                                    *used_fallback = true;
+                                }
+                            }
+                            if !error.is_null() {
+                                unsafe {
+                                    *error = FastEmbedError::from_string(format!(
+                                        "Execution provider {} failed ({}); fell back to CPU",
+                                        execution_provider, e
+                                    ));
+                                }
+                            }
+                            Box::into_raw(Box::new(AutoEmbedderHandle {
+                                kind: AUTO_EMBEDDER_KIND_DENSE,
+                                model: AutoEmbedderModel::Dense(Box::new(embedding)),
+                            }))
+                        }
+                        Err(e2) => {
+                            if !error.is_null() {
+                                unsafe {
+                                    *error = FastEmbedError::from_string(format!(
+                                        "Failed to create text embedding: {}",
+                                        e2
+                                    ));
+                                }
+                            }
+                            ptr::null_mut()
+                        }
+                    }
+                }
+            }
+        }
+        Some(AUTO_EMBEDDER_KIND_SPARSE) => {
+            let model = match code.parse::<SparseModel>() {
+                Ok(m) => m,
+                Err(e) => {
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!("Invalid sparse model: {}", e));
+                        }
+                    }
+                    return ptr::null_mut();
+                }
+            };
+            let init_options = SparseInitOptions::new(model.clone())
+                .with_execution_providers(execution_providers_for(execution_provider));
+            match SparseTextEmbedding::try_new(init_options) {
+                Ok(embedding) => Box::into_raw(Box::new(AutoEmbedderHandle {
+                    kind: AUTO_EMBEDDER_KIND_SPARSE,
+                    model: AutoEmbedderModel::Sparse(Box::new(embedding)),
+                })),
+                Err(e) => {
+                    if execution_provider == EXECUTION_PROVIDER_CPU {
+                        if !error.is_null() {
+                            unsafe {
+                                *error = FastEmbedError::from_string(format!(
+                                    "Failed to create sparse text embedding: {}",
+                                    e
+                                ));
+                            }
+                        }
+                        return ptr::null_mut();
+                    }
+                    let cpu_options = SparseInitOptions::new(model)
+                        .with_execution_providers(execution_providers_for(EXECUTION_PROVIDER_CPU));
+                    match SparseTextEmbedding::try_new(cpu_options) {
+                        Ok(embedding) => {
+                            if !used_fallback.is_null() {
+                                unsafe {
+                                    *used_fallback = true;
+                                }
+                            }
+                            if !error.is_null() {
+                                unsafe {
+                                    *error = FastEmbedError::from_string(format!(
+                                        "Execution provider {} failed ({}); fell back to CPU",
+                                        execution_provider, e
+                                    ));
+                                }
+                            }
+                            Box::into_raw(Box::new(AutoEmbedderHandle {
+                                kind: AUTO_EMBEDDER_KIND_SPARSE,
+                                model: AutoEmbedderModel::Sparse(Box::new(embedding)),
+                            }))
+                        }
+                        Err(e2) => {
+                            if !error.is_null() {
+                                unsafe {
+                                    *error = FastEmbedError::from_string(format!(
+                                        "Failed to create sparse text embedding: {}",
+                                        e2
+                                    ));
+                                }
+                            }
+                            ptr::null_mut()
+                        }
+                    }
+                }
+            }
+        }
+        Some(AUTO_EMBEDDER_KIND_IMAGE) => {
+            let model = match code.parse::<ImageEmbeddingModel>() {
+                Ok(m) => m,
+                Err(e) => {
+                    if !error.is_null() {
+                        unsafe {
+                            *error = FastEmbedError::from_string(format!("Invalid image model: {}", e));
+                        }
+                    }
+                    return ptr::null_mut();
+                }
+            };
+            let init_options = ImageInitOptions::new(model.clone())
+                .with_execution_providers(execution_providers_for(execution_provider));
+            match ImageEmbedding::try_new(init_options) {
+                Ok(embedding) => Box::into_raw(Box::new(AutoEmbedderHandle {
+                    kind: AUTO_EMBEDDER_KIND_IMAGE,
+                    model: AutoEmbedderModel::Image(Box::new(embedding)),
+                })),
+                Err(e) => {
+                    if execution_provider == EXECUTION_PROVIDER_CPU {
+                        if !error.is_null() {
+                            unsafe {
+                                *error =
+                                    FastEmbedError::from_string(format!("Failed to create image embedding: {}", e));
+                            }
+                        }
+                        return ptr::null_mut();
+                    }
+                    let cpu_options = ImageInitOptions::new(model)
+                        .with_execution_providers(execution_providers_for(EXECUTION_PROVIDER_CPU));
+                    match ImageEmbedding::try_new(cpu_options) {
+                        Ok(embedding) => {
+                            if !used_fallback.is_null() {
+                                unsafe {
+                                    *used_fallback = true;
+                                }
+                            }
+                            if !error.is_null() {
+                                unsafe {
+                                    *error = FastEmbedError::from_string(format!(
+                                        "Execution provider {} failed ({}); fell back to CPU",
+                                        execution_provider, e
+                                    ));
+                                }
+                            }
+                            Box::into_raw(Box::new(AutoEmbedderHandle {
+                                kind: AUTO_EMBEDDER_KIND_IMAGE,
+                                model: AutoEmbedderModel::Image(Box::new(embedding)),
+                            }))
+                        }
+                        Err(e2) => {
+                            if !error.is_null() {
+                                unsafe {
+                                    *error = FastEmbedError::from_string(format!(
+                                        "Failed to create image embedding: {}",
+                                        e2
+                                    ));
+                                }
+                            }
+                            ptr::null_mut()
+                        }
+                    }
+                }
+            }
+        }
+        None => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(format!("Unknown model code: {}", code));
+                }
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn fastembed_auto_embedder_kind(handle: *mut AutoEmbedderHandle) -> i32 {
+    if handle.is_null() {
+        return -1;
+    }
+    unsafe { (*handle).kind }
+}
+
+#[no_mangle]
+pub extern "C" fn fastembed_embed_auto(
+    handle: *mut AutoEmbedderHandle,
+    inputs: *const *const c_char,
+    count: usize,
+    error: *mut *mut FastEmbedError,
+) -> *mut FloatArrayVec {
+    if handle.is_null() || inputs.is_null() {
+        if !error.is_null() {
+            unsafe {
+                *error = FastEmbedError::from_string("Null pointer provided".to_string());
+            }
+        }
+        return ptr::null_mut();
+    }
+
+    let handle = unsafe { &mut *handle };
+    let input_slice = unsafe { slice::from_raw_parts(inputs, count) };
+
+    let mut input_vec = Vec::new();
+    for &input_ptr in input_slice {
+        if input_ptr.is_null() {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string("Null input pointer in array".to_string());
+                }
+            }
+            return ptr::null_mut();
+        }
+        let input = unsafe { CStr::from_ptr(input_ptr).to_str() };
+        match input {
+            Ok(s) => input_vec.push(s.to_string()),
+            Err(e) => {
+                if !error.is_null() {
+                    unsafe {
+                        *error = FastEmbedError::from_string(format!("Invalid UTF-8 in input: {}", e));
+                    }
+                }
+                return ptr::null_mut();
+            }
+        }
+    }
+
+    let result = match &mut handle.model {
+        AutoEmbedderModel::Dense(embedding) => embedding.embed(input_vec, None),
+        AutoEmbedderModel::Image(embedding) => embedding.embed(input_vec, None),
+        AutoEmbedderModel::Sparse(_) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(
+                        "Sparse models don't produce dense output; call fastembed_sparse_text_embedding_embed directly"
+                            .to_string(),
+                    );
+                }
+            }
+            return ptr::null_mut();
+        }
+    };
+
+    match result {
+        Ok(embeddings) => {
+            let mut arrays: Vec<FloatArray> = embeddings
+                .into_iter()
+                .map(|emb| {
+                    let mut boxed_slice = emb.into_boxed_slice();
+                    let len = boxed_slice.len();
+                    let data = boxed_slice.as_mut_ptr();
+                    std::mem::forget(boxed_slice);
+                    FloatArray { data, len }
+                })
+                .collect();
+
+            let len = arrays.len();
+            let arrays_ptr = arrays.as_mut_ptr();
+            std::mem::forget(arrays);
+
+            Box::into_raw(Box::new(FloatArrayVec {
+                arrays: arrays_ptr,
+                len,
+            }))
+        }
+        Err(e) => {
+            if !error.is_null() {
+                unsafe {
+                    *error = FastEmbedError::from_string(format!("Auto embedding failed: {}", e));
+                }
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Frees a handle returned by `fastembed_embed_auto_new`.
+#[no_mangle]
+pub extern "C" fn fastembed_auto_embedder_free(handle: *mut AutoEmbedderHandle) {
+    if !handle.is_null() {
+        unsafe {
+            let _ = Box::from_raw(handle);
+        }
+    }
+}
+
 // Memory cleanup functions
+/// Frees a `FloatArrayVec`, including each contained `FloatArray`'s backing buffer.
 #[no_mangle]
 pub extern "C" fn fastembed_float_array_vec_free(vec: *mut FloatArrayVec) {
     if !vec.is_null() {
@@ -702,6 +2245,7 @@ pub extern "C" fn fastembed_float_array_vec_free(vec: *mut FloatArrayVec) {
     }
 }
 
+/// Frees a `SparseEmbeddingVec`, including each contained embedding's indices/values buffers.
 #[no_mangle]
 pub extern "C" fn fastembed_sparse_embedding_vec_free(vec: *mut SparseEmbeddingVec) {
     if !vec.is_null() {
@@ -720,6 +2264,7 @@ pub extern "C" fn fastembed_sparse_embedding_vec_free(vec: *mut SparseEmbeddingV
     }
 }
 
+/// Frees a `RerankResultVec`, including each result's optional `document` string.
 #[no_mangle]
 pub extern "C" fn fastembed_rerank_result_vec_free(vec: *mut RerankResultVec) {
     if !vec.is_null() {
@@ -735,12 +2280,19 @@ pub extern "C" fn fastembed_rerank_result_vec_free(vec: *mut RerankResultVec) {
     }
 }
 
+// Model kind tags for `ModelInfoC::model_kind`, shared with `fastembed_auto_embedder_kind`.
+const MODEL_KIND_DENSE: i32 = AUTO_EMBEDDER_KIND_DENSE;
+const MODEL_KIND_SPARSE: i32 = AUTO_EMBEDDER_KIND_SPARSE;
+const MODEL_KIND_IMAGE: i32 = AUTO_EMBEDDER_KIND_IMAGE;
+const MODEL_KIND_RERANK: i32 = 3;
+
 // Model Information Structures
 #[repr(C)]
 pub struct ModelInfoC {
     pub model_code: *mut c_char,
     pub description: *mut c_char,
     pub dim: usize,
+    pub model_kind: i32,
 }
 
 #[repr(C)]
@@ -758,11 +2310,12 @@ pub extern "C" fn fastembed_text_embedding_list_supported_models() -> *mut Model
     for model in models {
         let model_code = CString::new(model.model_code).unwrap_or_else(|_| CString::new("").unwrap());
         let description = CString::new(model.description).unwrap_or_else(|_| CString::new("").unwrap());
-        
+
         model_infos.push(ModelInfoC {
             model_code: model_code.into_raw(),
             description: description.into_raw(),
             dim: model.dim,
+            model_kind: MODEL_KIND_DENSE,
         });
     }
 
@@ -776,6 +2329,7 @@ pub extern "C" fn fastembed_text_embedding_list_supported_models() -> *mut Model
     }))
 }
 
+/// Frees a `ModelInfoVec` returned by any `fastembed_*_list_supported_models` function.
 #[no_mangle]
 pub extern "C" fn fastembed_model_info_vec_free(vec: *mut ModelInfoVec) {
     if !vec.is_null() {
@@ -803,11 +2357,12 @@ pub extern "C" fn fastembed_sparse_text_embedding_list_supported_models() -> *mu
     for model in models {
         let model_code = CString::new(model.model_code).unwrap_or_else(|_| CString::new("").unwrap());
         let description = CString::new(model.description).unwrap_or_else(|_| CString::new("").unwrap());
-        
+
         model_infos.push(ModelInfoC {
             model_code: model_code.into_raw(),
             description: description.into_raw(),
             dim: model.dim,
+            model_kind: MODEL_KIND_SPARSE,
         });
     }
 
@@ -830,11 +2385,12 @@ pub extern "C" fn fastembed_image_embedding_list_supported_models() -> *mut Mode
     for model in models {
         let model_code = CString::new(model.model_code).unwrap_or_else(|_| CString::new("").unwrap());
         let description = CString::new(model.description).unwrap_or_else(|_| CString::new("").unwrap());
-        
+
         model_infos.push(ModelInfoC {
             model_code: model_code.into_raw(),
             description: description.into_raw(),
             dim: model.dim,
+            model_kind: MODEL_KIND_IMAGE,
         });
     }
 
@@ -857,11 +2413,12 @@ pub extern "C" fn fastembed_text_rerank_list_supported_models() -> *mut ModelInf
     for model in models {
         let model_code = CString::new(model.model_code).unwrap_or_else(|_| CString::new("").unwrap());
         let description = CString::new(model.description).unwrap_or_else(|_| CString::new("").unwrap());
-        
+
         model_infos.push(ModelInfoC {
             model_code: model_code.into_raw(),
             description: description.into_raw(),
             dim: 0, // Reranker models don't have dimensions
+            model_kind: MODEL_KIND_RERANK,
         });
     }
 
@@ -874,3 +2431,140 @@ pub extern "C" fn fastembed_text_rerank_list_supported_models() -> *mut ModelInf
         len,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cosine_similarity_identical_vectors_is_one() {
+        let a = [1.0, 2.0, 3.0];
+        assert!((cosine_similarity(&a, &a) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cosine_similarity_orthogonal_vectors_is_zero() {
+        let a = [1.0, 0.0];
+        let b = [0.0, 1.0];
+        assert!((cosine_similarity(&a, &b)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cosine_similarity_zero_vector_is_zero_not_nan() {
+        let a = [0.0, 0.0];
+        let b = [1.0, 2.0];
+        assert_eq!(cosine_similarity(&a, &b), 0.0);
+    }
+
+    #[test]
+    fn sparse_dot_overlapping_indices() {
+        let query = SparseEmbedding {
+            indices: vec![0, 2, 5],
+            values: vec![1.0, 2.0, 3.0],
+        };
+        let doc = SparseEmbedding {
+            indices: vec![2, 5, 9],
+            values: vec![4.0, 5.0, 6.0],
+        };
+        // Overlap is index 2 (2*4=8) and index 5 (3*5=15).
+        assert_eq!(sparse_dot(&query, &doc), 23.0);
+    }
+
+    #[test]
+    fn sparse_dot_no_overlap_is_zero() {
+        let query = SparseEmbedding {
+            indices: vec![0, 1],
+            values: vec![1.0, 1.0],
+        };
+        let doc = SparseEmbedding {
+            indices: vec![2, 3],
+            values: vec![1.0, 1.0],
+        };
+        assert_eq!(sparse_dot(&query, &doc), 0.0);
+    }
+
+    #[test]
+    fn sparse_dot_duplicate_query_index_counted_once() {
+        // Guards the `seen` dedup: a malformed query with a repeated index must not
+        // double-count its contribution against the same doc index.
+        let query = SparseEmbedding {
+            indices: vec![1, 1],
+            values: vec![2.0, 2.0],
+        };
+        let doc = SparseEmbedding {
+            indices: vec![1],
+            values: vec![3.0],
+        };
+        assert_eq!(sparse_dot(&query, &doc), 6.0);
+    }
+
+    #[test]
+    fn ranks_from_scores_descending_order() {
+        // Index 1 has the highest score (rank 1), index 2 the lowest (rank 3).
+        assert_eq!(ranks_from_scores(&[5.0, 9.0, 1.0]), vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn ranks_from_scores_ties_get_stable_order() {
+        assert_eq!(ranks_from_scores(&[1.0, 1.0]), vec![1, 2]);
+    }
+
+    #[test]
+    fn ranks_from_scores_empty_is_empty() {
+        assert_eq!(ranks_from_scores(&[]), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn min_max_normalize_scales_into_zero_one() {
+        assert_eq!(min_max_normalize(&[0.0, 5.0, 10.0]), vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn min_max_normalize_degenerate_range_is_one_half() {
+        // All-equal scores would otherwise divide by zero; every entry maps to 0.5.
+        assert_eq!(min_max_normalize(&[3.0, 3.0, 3.0]), vec![0.5, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn min_max_normalize_empty_is_empty() {
+        assert_eq!(min_max_normalize(&[]), Vec::<f32>::new());
+    }
+
+    #[test]
+    fn rank_fusion_scores_merges_multiple_ranked_lists() {
+        // Doc 1 is top of both lists, so it should win overall.
+        let dense: &[usize] = &[1, 0, 2];
+        let sparse: &[usize] = &[1, 2, 0];
+        let merged = rank_fusion_scores(&[dense, sparse], 60.0);
+        assert_eq!(merged[0], 1);
+        assert_eq!(merged.len(), 3);
+    }
+
+    #[test]
+    fn rank_fusion_scores_document_missing_from_some_lists() {
+        // Doc 9 only appears in one list; it should still be included, ranked behind
+        // documents that show up in both.
+        let list_a: &[usize] = &[0, 1];
+        let list_b: &[usize] = &[1, 9];
+        let merged = rank_fusion_scores(&[list_a, list_b], 60.0);
+        assert_eq!(merged[0], 1);
+        assert!(merged.contains(&9));
+        assert_eq!(merged.len(), 3);
+    }
+
+    #[test]
+    fn rank_fusion_scores_ties_broken_by_ascending_doc_id() {
+        // Symmetric lists give doc 3 and doc 5 the exact same accumulated score;
+        // the tie must resolve to ascending doc id, not list order.
+        let list_a: &[usize] = &[5, 3];
+        let list_b: &[usize] = &[3, 5];
+        let merged = rank_fusion_scores(&[list_a, list_b], 60.0);
+        assert_eq!(merged, vec![3, 5]);
+    }
+
+    #[test]
+    fn rank_fusion_scores_empty_lists_is_empty() {
+        let empty: Vec<&[usize]> = Vec::new();
+        assert_eq!(rank_fusion_scores(&empty, 60.0), Vec::<usize>::new());
+    }
+}